@@ -1,9 +1,10 @@
 #[cfg(feature = "dashmap")]
 use dashmap::DashMap;
-#[cfg(not(feature = "dashmap"))]
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
 
-use std::sync::Arc;
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
 
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum Error {
@@ -18,16 +19,178 @@ pub enum Error {
 
     #[error("Failed to send shutdown signal to worker {0:?}")]
     SendShutdownSignal(#[from] std::sync::mpsc::SendError<()>),
+
+    #[error("Worker {worker_id} panicked {retries} time(s) and was not recovered")]
+    WorkerPanicked { worker_id: String, retries: u32 },
+
+    #[error("Worker {worker_id} did not shut down within the timeout and was detached")]
+    ShutdownTimeout { worker_id: String },
 }
 
 pub type Result<T> = core::result::Result<T, Error>;
 
+/// The last known state of a worker, as reported by its own `handle` closure
+/// through the status handle passed to it. The pool also writes it: `Stopping`
+/// when the worker is stopped, and for a supervised worker `Idle` on each
+/// respawn and `Errored` when supervision gives up after a panic.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WorkerStatus {
+    Idle,
+    Busy,
+    Stopping,
+    Errored(String),
+}
+
+/// The closure a caller hands to `spawn`/`spawn_supervised`: given the
+/// worker's `id`, a clone of the pool's `state`, the shutdown signal, and a
+/// handle to push [`WorkerStatus`] updates through, it does the actual
+/// `std::thread::spawn` and returns the resulting `JoinHandle`.
+type Handle<Id, State, Return> = Arc<
+    dyn Fn(
+            Id,
+            State,
+            std::sync::mpsc::Receiver<()>,
+            Arc<Mutex<WorkerStatus>>,
+        ) -> std::thread::JoinHandle<Return>
+        + Send
+        + Sync,
+>;
+
+/// Set, and notified on, just before a thread returns for good (i.e. not on
+/// a supervised respawn). Lets a bounded shutdown wait on it with a timeout
+/// instead of blocking on `JoinHandle::join` forever.
+type Finished = Arc<(Mutex<bool>, Condvar)>;
+
+/// Governs whether a supervised worker is respawned after its thread ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestartPolicy {
+    /// Never respawn, whether the thread returned normally or panicked.
+    Never,
+    /// Always respawn, whether the thread returned normally or panicked.
+    Always,
+    /// Respawn only after a panic; a normal return ends supervision.
+    OnPanic,
+    /// Respawn after a panic, up to `u32` times; a normal return ends
+    /// supervision.
+    MaxRetries(u32),
+}
+
+/// A lifecycle transition broadcast to every subscriber returned by
+/// [`ThreadPool::events`].
+#[derive(Debug, Clone)]
+pub enum Event<Id, Return> {
+    Spawned(Id),
+    Stopping(Id),
+    Stopped(Id),
+    Finished(Id, Return),
+    Failed(Id, String),
+}
+
+/// A point-in-time snapshot of a worker, returned by [`ThreadPool::get_worker_info`].
+#[derive(Debug, Clone)]
+pub struct WorkerInfo<Id> {
+    pub id: Id,
+    pub name: String,
+    pub status: WorkerStatus,
+    pub started_at: Instant,
+}
+
+/// A unit of work for the priority queue execution mode, ordered by
+/// `priority` alone so a [`std::collections::BinaryHeap`] always yields the
+/// highest-priority job first.
+pub struct Job<State, Return> {
+    pub priority: u64,
+    pub work: Arc<dyn Fn(State) -> Return + Send + Sync>,
+}
+
+impl<State, Return> Job<State, Return> {
+    pub fn new(priority: u64, work: Arc<dyn Fn(State) -> Return + Send + Sync>) -> Self {
+        Self { priority, work }
+    }
+}
+
+impl<State, Return> PartialEq for Job<State, Return> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<State, Return> Eq for Job<State, Return> {}
+
+impl<State, Return> PartialOrd for Job<State, Return> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<State, Return> Ord for Job<State, Return> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority.cmp(&other.priority)
+    }
+}
+
+/// Shared priority queue backing [`ThreadPool::submit`]: a `BinaryHeap`
+/// guarded by a `Mutex` + `Condvar` pair so queue workers can block when
+/// there is no work, and wake either on a new submission or on shutdown.
+struct Queue<State, Return> {
+    heap: Mutex<QueueState<State, Return>>,
+    condvar: Condvar,
+}
+
+struct QueueState<State, Return> {
+    jobs: BinaryHeap<Job<State, Return>>,
+    shutdown: bool,
+}
+
+impl<State, Return> Queue<State, Return> {
+    fn new() -> Self {
+        Self {
+            heap: Mutex::new(QueueState {
+                jobs: BinaryHeap::new(),
+                shutdown: false,
+            }),
+            condvar: Condvar::new(),
+        }
+    }
+
+    fn push(&self, job: Job<State, Return>) {
+        self.heap.lock().unwrap().jobs.push(job);
+        self.condvar.notify_one();
+    }
+
+    /// Blocks until a job is available or the queue is shut down, in which
+    /// case it returns `None`.
+    fn pop(&self) -> Option<Job<State, Return>> {
+        let mut state = self.heap.lock().unwrap();
+        loop {
+            if let Some(job) = state.jobs.pop() {
+                return Some(job);
+            }
+            if state.shutdown {
+                return None;
+            }
+            state = self.condvar.wait(state).unwrap();
+        }
+    }
+
+    fn shutdown(&self) {
+        self.heap.lock().unwrap().shutdown = true;
+        self.condvar.notify_all();
+    }
+}
+
 pub struct ThreadPool<Id: std::fmt::Debug + Clone + Eq + std::hash::Hash, State: Clone, Return> {
     #[cfg(not(feature = "dashmap"))]
     workers: HashMap<Id, Worker<Id, State, Return>>,
     #[cfg(feature = "dashmap")]
     workers: DashMap<Id, Worker<Id, State, Return>>,
     state: State,
+    queue: Arc<Queue<State, Return>>,
+    // Paired with a `finished` signal per worker, same as `Worker`, so
+    // `shutdown_queue_workers_timeout` can bound each join the same way
+    // `Worker::stop_timeout` does.
+    queue_workers: Mutex<Vec<(std::thread::JoinHandle<()>, Finished)>>,
+    subscribers: Mutex<Vec<std::sync::mpsc::Sender<Event<Id, Return>>>>,
 }
 
 impl<Id: std::fmt::Debug + Clone + Eq + std::hash::Hash, State: Clone, Return>
@@ -40,6 +203,151 @@ impl<Id: std::fmt::Debug + Clone + Eq + std::hash::Hash, State: Clone, Return>
             #[cfg(feature = "dashmap")]
             workers: DashMap::new(),
             state,
+            queue: Arc::new(Queue::new()),
+            queue_workers: Mutex::new(Vec::new()),
+            subscribers: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Subscribes to this pool's lifecycle events. Each call returns an
+    /// independent receiver; every subscriber sees every transition.
+    pub fn events(&self) -> std::sync::mpsc::Receiver<Event<Id, Return>>
+    where
+        Return: Clone,
+    {
+        let (tx, rx) = std::sync::mpsc::channel();
+        self.subscribers.lock().unwrap().push(tx);
+        rx
+    }
+
+    fn broadcast(&self, event: Event<Id, Return>)
+    where
+        Return: Clone,
+    {
+        let mut subscribers = self.subscribers.lock().unwrap();
+        subscribers.retain(|tx| tx.send(event.clone()).is_ok());
+    }
+
+    /// Stops `worker`, broadcasting the matching [`Event`] transitions
+    /// around it. Shared by the `stop` implementations below.
+    fn reap(&self, id: Id, worker: Worker<Id, State, Return>) -> Result<()>
+    where
+        Return: Clone,
+    {
+        self.broadcast(Event::Stopping(id.clone()));
+        match worker.stop() {
+            Ok(value) => {
+                self.broadcast(Event::Finished(id.clone(), value));
+                self.broadcast(Event::Stopped(id));
+                Ok(())
+            }
+            Err(e) => {
+                self.broadcast(Event::Failed(id, e.to_string()));
+                Err(e)
+            }
+        }
+    }
+
+    /// Like [`ThreadPool::reap`], but through [`Worker::stop_timeout`].
+    fn reap_timeout(
+        &self,
+        id: Id,
+        worker: Worker<Id, State, Return>,
+        timeout: Duration,
+    ) -> Result<()>
+    where
+        Return: Clone,
+    {
+        self.broadcast(Event::Stopping(id.clone()));
+        match worker.stop_timeout(timeout) {
+            Ok(value) => {
+                self.broadcast(Event::Finished(id.clone(), value));
+                self.broadcast(Event::Stopped(id));
+                Ok(())
+            }
+            Err(e) => {
+                self.broadcast(Event::Failed(id, e.to_string()));
+                Err(e)
+            }
+        }
+    }
+
+    /// Enqueues `job` for the priority queue workers started by
+    /// [`ThreadPool::spawn_queue_workers`]. Higher `priority` runs first;
+    /// this is independent of the named-worker API above. Jobs are
+    /// fire-and-forget: there is no way to retrieve a job's return value or
+    /// learn of its completion through [`ThreadPool::events`] — `Return`
+    /// here only exists to share the pool's generic parameter. A job that
+    /// panics is caught and logged rather than taking down its queue
+    /// worker thread, so the worker keeps pulling subsequent jobs.
+    pub fn submit(&self, job: Job<State, Return>) {
+        tracing::debug!("Submitting job with priority {}", job.priority);
+        self.queue.push(job);
+    }
+
+    /// Starts `count` generic workers that pull jobs from the shared
+    /// priority queue, highest priority first, blocking when it is empty.
+    pub fn spawn_queue_workers(&self, count: usize)
+    where
+        State: Send + 'static,
+        Return: Send + 'static,
+    {
+        let mut workers = self.queue_workers.lock().unwrap();
+        for _ in 0..count {
+            let queue = self.queue.clone();
+            let state = self.state.clone();
+            let finished = Arc::new((Mutex::new(false), Condvar::new()));
+            let thread_finished = finished.clone();
+            let thread = std::thread::spawn(move || {
+                while let Some(job) = queue.pop() {
+                    let state = state.clone();
+                    let work = job.work.clone();
+                    if let Err(panic) =
+                        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| work(state)))
+                    {
+                        tracing::error!("Queue worker job panicked: {:?}", panic);
+                    }
+                }
+                *thread_finished.0.lock().unwrap() = true;
+                thread_finished.1.notify_all();
+            });
+            workers.push((thread, finished));
+        }
+    }
+
+    /// Signals every queue worker to stop once the queue is empty (jobs
+    /// already queued still run), wakes any that are blocked waiting for
+    /// work, and joins them.
+    pub fn shutdown_queue_workers(&self) {
+        self.queue.shutdown();
+        let mut workers = self.queue_workers.lock().unwrap();
+        for (worker, _finished) in workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+
+    /// Like [`ThreadPool::shutdown_queue_workers`], but gives up waiting on
+    /// a queue worker's thread after `timeout` and detaches it instead of
+    /// blocking forever, the same way [`Worker::stop_timeout`] does for
+    /// named workers.
+    fn shutdown_queue_workers_timeout(&self, timeout: Duration) {
+        self.queue.shutdown();
+        let mut workers = self.queue_workers.lock().unwrap();
+        for (worker, finished) in workers.drain(..) {
+            let (lock, condvar) = &*finished;
+            let guard = lock.lock().unwrap();
+            let (_guard, wait_result) = condvar
+                .wait_timeout_while(guard, timeout, |finished| !*finished)
+                .unwrap();
+
+            if wait_result.timed_out() {
+                tracing::warn!(
+                    "Queue worker did not shut down within {:?}, detaching its thread",
+                    timeout
+                );
+            } else {
+                let _ = worker.join();
+            }
         }
     }
 
@@ -51,116 +359,549 @@ impl<Id: std::fmt::Debug + Clone + Eq + std::hash::Hash, State: Clone, Return>
         ids
     }
 
+    /// Returns a snapshot of every worker currently tracked by the pool,
+    /// without joining any of their threads.
+    pub fn get_worker_info(&self) -> HashMap<Id, WorkerInfo<Id>> {
+        #[cfg(not(feature = "dashmap"))]
+        let info = self
+            .workers
+            .iter()
+            .map(|(id, worker)| (id.clone(), worker.info()))
+            .collect();
+        #[cfg(feature = "dashmap")]
+        let info = self
+            .workers
+            .iter()
+            .map(|r| (r.id.clone(), r.info()))
+            .collect();
+        info
+    }
+
+    #[cfg(not(feature = "dashmap"))]
+    pub fn spawn(&mut self, id: Id, handle: Handle<Id, State, Return>) -> Result<()>
+    where
+        Id: Send + 'static,
+        State: Send + 'static,
+        Return: Send + Clone + 'static,
+    {
+        if self.workers.contains_key(&id) {
+            return Err(Error::WorkerAlreadyExist(format!("{:?}", id)));
+        }
+
+        tracing::info!("Spawning worker {:?}...", id);
+        let worker = Worker::new(id.clone(), self.state.clone(), handle);
+        self.workers.insert(id.clone(), worker);
+        self.broadcast(Event::Spawned(id));
+
+        Ok(())
+    }
+
     #[cfg(not(feature = "dashmap"))]
-    pub fn spawn(
+    pub fn spawn_supervised(
         &mut self,
         id: Id,
-        handle: Arc<
-            dyn Fn(Id, State, std::sync::mpsc::Receiver<()>) -> std::thread::JoinHandle<Return>
-                + Send
-                + Sync,
-        >,
-    ) -> Result<()> {
+        policy: RestartPolicy,
+        handle: Handle<Id, State, Return>,
+    ) -> Result<()>
+    where
+        Id: Send + 'static,
+        State: Send + 'static,
+        Return: Send + Clone + 'static,
+    {
+        if self.workers.contains_key(&id) {
+            return Err(Error::WorkerAlreadyExist(format!("{:?}", id)));
+        }
+
+        tracing::info!("Spawning supervised worker {:?} under {:?}...", id, policy);
+        let worker = Worker::new_supervised(id.clone(), self.state.clone(), policy, handle);
+        self.workers.insert(id.clone(), worker);
+        self.broadcast(Event::Spawned(id));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "dashmap")]
+    pub fn spawn(&self, id: Id, handle: Handle<Id, State, Return>) -> Result<()>
+    where
+        Id: Send + 'static,
+        State: Send + 'static,
+        Return: Send + Clone + 'static,
+    {
         if self.workers.contains_key(&id) {
             return Err(Error::WorkerAlreadyExist(format!("{:?}", id)));
         }
 
         tracing::info!("Spawning worker {:?}...", id);
-        self.workers
-            .insert(id.clone(), Worker::new(id, self.state.clone(), handle));
+        let worker = Worker::new(id.clone(), self.state.clone(), handle);
+        self.workers.insert(id.clone(), worker);
+        self.broadcast(Event::Spawned(id));
 
         Ok(())
     }
 
     #[cfg(feature = "dashmap")]
-    pub fn spawn(
+    pub fn spawn_supervised(
         &self,
         id: Id,
-        handle: Arc<
-            dyn Fn(Id, State, std::sync::mpsc::Receiver<()>) -> std::thread::JoinHandle<Return>
-                + Send
-                + Sync,
-        >,
-    ) -> Result<()> {
+        policy: RestartPolicy,
+        handle: Handle<Id, State, Return>,
+    ) -> Result<()>
+    where
+        Id: Send + 'static,
+        State: Send + 'static,
+        Return: Send + Clone + 'static,
+    {
         if self.workers.contains_key(&id) {
             return Err(Error::WorkerAlreadyExist(format!("{:?}", id)));
         }
 
-        tracing::info!("Spawning worker {:?}...", id);
-        self.workers
-            .insert(id.clone(), Worker::new(id, self.state.clone(), handle));
+        tracing::info!("Spawning supervised worker {:?} under {:?}...", id, policy);
+        let worker = Worker::new_supervised(id.clone(), self.state.clone(), policy, handle);
+        self.workers.insert(id.clone(), worker);
+        self.broadcast(Event::Spawned(id));
 
         Ok(())
     }
 
     #[cfg(not(feature = "dashmap"))]
-    pub fn stop(&mut self, id: Id) -> Result<()> {
+    pub fn stop(&mut self, id: Id) -> Result<()>
+    where
+        Return: Clone,
+    {
+        match self.workers.remove(&id) {
+            Some(worker) => self.reap(id, worker),
+            None => Err(Error::WorkerNotFound(format!("{:?}", id))),
+        }
+    }
+
+    #[cfg(feature = "dashmap")]
+    pub fn stop(&self, id: Id) -> Result<()>
+    where
+        Return: Clone,
+    {
+        match self.workers.remove(&id) {
+            Some((_, worker)) => self.reap(id, worker),
+            None => Err(Error::WorkerNotFound(format!("{:?}", id))),
+        }
+    }
+
+    /// Like [`ThreadPool::stop`], but gives up waiting on the worker's
+    /// thread after `timeout` and detaches it instead of blocking forever.
+    #[cfg(not(feature = "dashmap"))]
+    pub fn stop_timeout(&mut self, id: Id, timeout: Duration) -> Result<()>
+    where
+        Return: Clone,
+    {
         match self.workers.remove(&id) {
-            Some(worker) => worker.stop(),
+            Some(worker) => self.reap_timeout(id, worker, timeout),
             None => Err(Error::WorkerNotFound(format!("{:?}", id))),
         }
     }
 
     #[cfg(feature = "dashmap")]
-    pub fn stop(&self, id: Id) -> Result<()> {
+    pub fn stop_timeout(&self, id: Id, timeout: Duration) -> Result<()>
+    where
+        Return: Clone,
+    {
         match self.workers.remove(&id) {
-            Some((_, worker)) => worker.stop(),
+            Some((_, worker)) => self.reap_timeout(id, worker, timeout),
             None => Err(Error::WorkerNotFound(format!("{:?}", id))),
         }
     }
+
+    /// Stops every named worker and the priority queue workers, allowing up
+    /// to `timeout` for each named worker's thread to finish. Keeps going
+    /// after a worker times out so one wedged worker can't stall the rest
+    /// of the pool's shutdown; the first error encountered, if any, is
+    /// returned once every worker has been dealt with.
+    #[cfg(not(feature = "dashmap"))]
+    pub fn shutdown(&mut self, timeout: Duration) -> Result<()>
+    where
+        Return: Clone,
+    {
+        self.shutdown_queue_workers_timeout(timeout);
+        let mut first_err = None;
+        for id in self.ids() {
+            if let Err(e) = self.stop_timeout(id, timeout) {
+                tracing::error!("Error while shutting down pool: {}", e);
+                first_err.get_or_insert(e);
+            }
+        }
+        first_err.map_or(Ok(()), Err)
+    }
+
+    #[cfg(feature = "dashmap")]
+    pub fn shutdown(&self, timeout: Duration) -> Result<()>
+    where
+        Return: Clone,
+    {
+        self.shutdown_queue_workers_timeout(timeout);
+        let mut first_err = None;
+        for id in self.ids() {
+            if let Err(e) = self.stop_timeout(id, timeout) {
+                tracing::error!("Error while shutting down pool: {}", e);
+                first_err.get_or_insert(e);
+            }
+        }
+        first_err.map_or(Ok(()), Err)
+    }
 }
 
+/// Bound applied to every worker's join when the pool is dropped, so a
+/// wedged worker can't hang `Drop` (and the whole process) forever.
+const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
+
 impl<Id: std::fmt::Debug + Clone + Eq + std::hash::Hash, State: Clone, Return> Drop
     for ThreadPool<Id, State, Return>
 {
     fn drop(&mut self) {
         tracing::warn!("ThreadPool is being dropped...");
+        self.shutdown_queue_workers_timeout(DEFAULT_SHUTDOWN_TIMEOUT);
         #[cfg(not(feature = "dashmap"))]
-        for (_, worker) in self.workers.drain() {
-            worker.stop().unwrap();
+        for (id, worker) in self.workers.drain() {
+            if let Err(e) = worker.stop_timeout(DEFAULT_SHUTDOWN_TIMEOUT) {
+                tracing::error!("Error while dropping worker {:?}: {}", id, e);
+            }
         }
         #[cfg(feature = "dashmap")]
         for worker in &self.workers {
-            let (_, worker) = self.workers.remove(&worker.id).unwrap();
-            worker.stop().unwrap();
+            let (id, worker) = self.workers.remove(&worker.id).unwrap();
+            if let Err(e) = worker.stop_timeout(DEFAULT_SHUTDOWN_TIMEOUT) {
+                tracing::error!("Error while dropping worker {:?}: {}", id, e);
+            }
         }
     }
 }
 
 struct Worker<Id: std::fmt::Debug + Clone, State, Return> {
     id: Id,
+    name: String,
     thread: std::thread::JoinHandle<Return>,
     phantom: std::marker::PhantomData<State>,
-    shutdown_tx: std::sync::mpsc::Sender<()>,
+    // Wrapped in `Arc<Mutex<_>>` so a supervised worker can swap in a fresh
+    // sender/receiver pair for each respawn attempt while `stop()` keeps
+    // talking to whichever attempt is currently running.
+    shutdown_tx: Arc<Mutex<std::sync::mpsc::Sender<()>>>,
+    status: Arc<Mutex<WorkerStatus>>,
+    started_at: Instant,
+    retries: Arc<Mutex<u32>>,
+    // Set, and broadcast on, just before `thread`'s closure returns for
+    // good (i.e. not on a supervised respawn). Lets `stop_timeout` wait on
+    // it with a bound instead of blocking on `thread.join()` forever.
+    finished: Finished,
 }
 
 impl<Id: std::fmt::Debug + Clone, State, Return> Worker<Id, State, Return> {
-    fn new(
+    fn new(id: Id, state: State, handle: Handle<Id, State, Return>) -> Self
+    where
+        Id: Send + 'static,
+        State: Send + 'static,
+        Return: Send + 'static,
+    {
+        let (shutdown_tx, shutdown_rx) = std::sync::mpsc::channel();
+        let status = Arc::new(Mutex::new(WorkerStatus::Idle));
+        let finished = Arc::new((Mutex::new(false), Condvar::new()));
+        let name = format!("{:?}", id);
+
+        let thread_id = id.clone();
+        let thread_status = status.clone();
+        let thread_finished = finished.clone();
+        let thread = std::thread::spawn(move || {
+            let result = handle(thread_id, state, shutdown_rx, thread_status).join();
+            *thread_finished.0.lock().unwrap() = true;
+            thread_finished.1.notify_all();
+            match result {
+                Ok(value) => value,
+                Err(panic) => std::panic::resume_unwind(panic),
+            }
+        });
+
+        Self {
+            id,
+            name,
+            thread,
+            phantom: std::marker::PhantomData,
+            shutdown_tx: Arc::new(Mutex::new(shutdown_tx)),
+            status,
+            started_at: Instant::now(),
+            retries: Arc::new(Mutex::new(0)),
+            finished,
+        }
+    }
+
+    /// Like [`Worker::new`], but installs a supervisor loop around `handle`:
+    /// when the spawned thread ends, the loop decides per `policy` whether
+    /// to treat the outcome as final or to invoke `handle` again under the
+    /// same `id`, handing it a fresh shutdown channel.
+    fn new_supervised(
         id: Id,
         state: State,
-        handle: Arc<
-            dyn Fn(Id, State, std::sync::mpsc::Receiver<()>) -> std::thread::JoinHandle<Return>
-                + Send
-                + Sync,
-        >,
-    ) -> Self {
+        policy: RestartPolicy,
+        handle: Handle<Id, State, Return>,
+    ) -> Self
+    where
+        Id: Send + 'static,
+        State: Clone + Send + 'static,
+        Return: Send + 'static,
+    {
         let (shutdown_tx, shutdown_rx) = std::sync::mpsc::channel();
+        let shutdown_tx = Arc::new(Mutex::new(shutdown_tx));
+        let status = Arc::new(Mutex::new(WorkerStatus::Idle));
+        let retries = Arc::new(Mutex::new(0u32));
+        let finished = Arc::new((Mutex::new(false), Condvar::new()));
+        let name = format!("{:?}", id);
+
+        let supervised_id = id.clone();
+        let supervised_status = status.clone();
+        let supervised_retries = retries.clone();
+        let supervised_shutdown_tx = shutdown_tx.clone();
+        let supervised_finished = finished.clone();
+
+        let thread = std::thread::spawn(move || {
+            let mark_finished = |finished: &Finished| {
+                *finished.0.lock().unwrap() = true;
+                finished.1.notify_all();
+            };
+
+            let mut shutdown_rx = shutdown_rx;
+            let value = loop {
+                let attempt = handle(
+                    supervised_id.clone(),
+                    state.clone(),
+                    shutdown_rx,
+                    supervised_status.clone(),
+                );
+
+                match attempt.join() {
+                    Ok(value) => {
+                        if policy != RestartPolicy::Always {
+                            break value;
+                        }
+
+                        tracing::info!(
+                            "Worker {:?} finished, restarting under {:?}...",
+                            supervised_id,
+                            policy
+                        );
+                        *supervised_status.lock().unwrap() = WorkerStatus::Idle;
+                        let (new_tx, new_rx) = std::sync::mpsc::channel();
+                        *supervised_shutdown_tx.lock().unwrap() = new_tx;
+                        shutdown_rx = new_rx;
+                    }
+                    Err(panic) => {
+                        let should_restart = match policy {
+                            RestartPolicy::Never => false,
+                            RestartPolicy::Always | RestartPolicy::OnPanic => true,
+                            RestartPolicy::MaxRetries(max) => {
+                                let mut retries = supervised_retries.lock().unwrap();
+                                *retries += 1;
+                                *retries <= max
+                            }
+                        };
+
+                        if !should_restart {
+                            *supervised_status.lock().unwrap() =
+                                WorkerStatus::Errored(format!("{:?}", panic));
+                            mark_finished(&supervised_finished);
+                            std::panic::resume_unwind(panic);
+                        }
+
+                        tracing::warn!(
+                            "Worker {:?} panicked, restarting under {:?}...",
+                            supervised_id,
+                            policy
+                        );
+                        *supervised_status.lock().unwrap() = WorkerStatus::Idle;
+                        let (new_tx, new_rx) = std::sync::mpsc::channel();
+                        *supervised_shutdown_tx.lock().unwrap() = new_tx;
+                        shutdown_rx = new_rx;
+                    }
+                }
+            };
+
+            mark_finished(&supervised_finished);
+            value
+        });
+
         Self {
-            id: id.clone(),
-            thread: handle(id, state, shutdown_rx),
+            id,
+            name,
+            thread,
             phantom: std::marker::PhantomData,
             shutdown_tx,
+            status,
+            started_at: Instant::now(),
+            retries,
+            finished,
+        }
+    }
+
+    fn info(&self) -> WorkerInfo<Id> {
+        WorkerInfo {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            status: self.status.lock().unwrap().clone(),
+            started_at: self.started_at,
         }
     }
 
-    fn stop(self) -> Result<()> {
+    fn stop(self) -> Result<Return> {
         tracing::info!("Stopping worker {:?}...", self.id);
-        self.shutdown_tx.send(())?;
-        self.thread.join().map_err(|e| Error::StopError {
-            worker_id: format!("{:?}", self.id),
-            error: format!("{:?}", e),
+        *self.status.lock().unwrap() = WorkerStatus::Stopping;
+        // A supervised worker that already gave up (e.g. `Never` after a
+        // panic, or `MaxRetries` exceeded) has exited and dropped its
+        // receiver, so this send fails deterministically; that just means
+        // there's nothing left to signal, not that the join below should be
+        // skipped.
+        let _ = self.shutdown_tx.lock().unwrap().send(());
+        let value = self.thread.join().map_err(|e| {
+            let retries = *self.retries.lock().unwrap();
+            if retries > 0 {
+                Error::WorkerPanicked {
+                    worker_id: format!("{:?}", self.id),
+                    retries,
+                }
+            } else {
+                Error::StopError {
+                    worker_id: format!("{:?}", self.id),
+                    error: format!("{:?}", e),
+                }
+            }
         })?;
         tracing::info!("Worker {:?} shutdown successfully", self.id);
-        Ok(())
+        Ok(value)
+    }
+
+    /// Like [`Worker::stop`], but gives up waiting for the thread to finish
+    /// after `timeout` instead of blocking forever. On timeout the thread is
+    /// left detached (dropping `self.thread` without joining it) and
+    /// [`Error::ShutdownTimeout`] is returned.
+    fn stop_timeout(self, timeout: Duration) -> Result<Return> {
+        tracing::info!("Stopping worker {:?} (timeout {:?})...", self.id, timeout);
+        *self.status.lock().unwrap() = WorkerStatus::Stopping;
+        // See the comment in `stop`: a worker that already gave up has
+        // dropped its receiver, so a failed send here is expected and must
+        // not stop us from waiting on `finished` below.
+        let _ = self.shutdown_tx.lock().unwrap().send(());
+
+        let (lock, condvar) = &*self.finished;
+        let guard = lock.lock().unwrap();
+        let (_guard, wait_result) = condvar
+            .wait_timeout_while(guard, timeout, |finished| !*finished)
+            .unwrap();
+
+        if wait_result.timed_out() {
+            tracing::warn!(
+                "Worker {:?} did not shut down within {:?}, detaching its thread",
+                self.id,
+                timeout
+            );
+            return Err(Error::ShutdownTimeout {
+                worker_id: format!("{:?}", self.id),
+            });
+        }
+
+        let value = self.thread.join().map_err(|e| {
+            let retries = *self.retries.lock().unwrap();
+            if retries > 0 {
+                Error::WorkerPanicked {
+                    worker_id: format!("{:?}", self.id),
+                    retries,
+                }
+            } else {
+                Error::StopError {
+                    worker_id: format!("{:?}", self.id),
+                    error: format!("{:?}", e),
+                }
+            }
+        })?;
+        tracing::info!("Worker {:?} shutdown successfully", self.id);
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    // `spawn_supervised` only needs `&mut self` without the `dashmap` feature.
+    #[cfg_attr(feature = "dashmap", allow(unused_mut))]
+    fn stop_timeout_reports_panic_once_supervision_gives_up() {
+        let mut pool: ThreadPool<u32, (), ()> = ThreadPool::new(());
+        let handle: Handle<u32, (), ()> =
+            Arc::new(|_id, _state, _shutdown_rx, _status| std::thread::spawn(|| panic!("boom")));
+        pool.spawn_supervised(1, RestartPolicy::MaxRetries(0), handle)
+            .unwrap();
+
+        // Give the panic time to happen and the supervisor loop to give up,
+        // dropping its shutdown receiver before we ever call `stop_timeout`.
+        std::thread::sleep(Duration::from_millis(200));
+
+        match pool.stop_timeout(1, Duration::from_secs(1)) {
+            Err(Error::WorkerPanicked { retries, .. }) => assert_eq!(retries, 1),
+            other => panic!("expected Error::WorkerPanicked, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn shutdown_does_not_wait_past_its_timeout_for_a_wedged_queue_worker() {
+        let pool: ThreadPool<u32, (), ()> = ThreadPool::new(());
+        pool.spawn_queue_workers(1);
+        pool.submit(Job::new(
+            0,
+            Arc::new(|_state| std::thread::sleep(Duration::from_secs(600))),
+        ));
+        // Let the queue worker pick the job up before we try to shut down.
+        std::thread::sleep(Duration::from_millis(50));
+
+        let started = Instant::now();
+        pool.shutdown_queue_workers_timeout(Duration::from_millis(200));
+        assert!(
+            started.elapsed() < Duration::from_secs(5),
+            "shutdown_queue_workers_timeout blocked on a wedged worker"
+        );
+    }
+
+    #[test]
+    fn a_panicking_job_does_not_stop_the_queue_worker_from_draining_the_rest() {
+        let state = Arc::new(Mutex::new(0u32));
+        let pool: ThreadPool<u32, Arc<Mutex<u32>>, ()> = ThreadPool::new(state.clone());
+        pool.spawn_queue_workers(1);
+
+        // Higher priority so it runs first, and panics.
+        pool.submit(Job::new(
+            1,
+            Arc::new(|_state: Arc<Mutex<u32>>| panic!("boom")),
+        ));
+        pool.submit(Job::new(
+            0,
+            Arc::new(|state: Arc<Mutex<u32>>| *state.lock().unwrap() += 1),
+        ));
+
+        pool.shutdown_queue_workers();
+        assert_eq!(*state.lock().unwrap(), 1);
+    }
+
+    #[test]
+    // `spawn` only needs `&mut self` without the `dashmap` feature.
+    #[cfg_attr(feature = "dashmap", allow(unused_mut))]
+    fn spawn_broadcasts_the_spawned_event_with_the_worker_id() {
+        let mut pool: ThreadPool<u32, (), ()> = ThreadPool::new(());
+        let events = pool.events();
+        let handle: Handle<u32, (), ()> = Arc::new(|_id, _state, shutdown_rx, _status| {
+            std::thread::spawn(move || {
+                let _ = shutdown_rx.recv();
+            })
+        });
+
+        pool.spawn(7, handle).unwrap();
+
+        match events.recv_timeout(Duration::from_secs(1)) {
+            Ok(Event::Spawned(id)) => assert_eq!(id, 7),
+            other => panic!("expected Event::Spawned(7), got {:?}", other),
+        }
+
+        pool.stop(7).unwrap();
     }
 }